@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use arrow::array::{Array, DictionaryArray, DictionaryKey, FixedSizeBinaryArray, PrimitiveArray};
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::ArrowDataType;
@@ -7,6 +9,7 @@ use super::utils::{dict_indices_decoder, extend_from_decoder, not_implemented, D
 use crate::parquet::encoding::hybrid_rle::gatherer::HybridRleGatherer;
 use crate::parquet::encoding::{hybrid_rle, Encoding};
 use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::indexes::{ColumnIndex, OffsetIndex};
 use crate::parquet::page::{split_buffer, DataPage, DictPage};
 use crate::read::deserialize::utils::filter::Filter;
 use crate::read::deserialize::utils::{self, BatchableCollector, GatheredHybridRle, PageValidity};
@@ -14,8 +17,16 @@ use crate::read::deserialize::utils::{self, BatchableCollector, GatheredHybridRl
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub(crate) enum StateTranslation<'a> {
-    Plain(&'a [u8], usize),
-    Dictionary(hybrid_rle::HybridRleDecoder<'a>, &'a Vec<u8>),
+    Plain(&'a [u8], usize, Option<Filter<'a>>),
+    Dictionary(
+        hybrid_rle::HybridRleDecoder<'a>,
+        &'a Vec<u8>,
+        Option<Filter<'a>>,
+    ),
+    /// BYTE_STREAM_SPLIT: the page buffer, the record size, the number of records already
+    /// consumed, and any pushed-down row filter. The buffer is never sliced (its bytes are
+    /// transposed, not laid out per-record), so consumption only ever advances the record index.
+    ByteStreamSplit(&'a [u8], usize, usize, Option<Filter<'a>>),
 }
 
 pub struct FixedSizeBinary {
@@ -23,6 +34,228 @@ pub struct FixedSizeBinary {
     pub size: usize,
 }
 
+/// The decoded, not-yet-finalized state of a fixed-size-binary column.
+///
+/// `Values` materializes every record into `values`, as required when the output is a plain
+/// `FixedSizeBinaryArray`. `Dict` is a "delayed dict" state used when the output is a
+/// `DictionaryArray`: only the dictionary-encoded keys (plus validity) are collected, and the
+/// dictionary's value bytes are attached as-is in `finalize_dict_array`, so a dictionary page is
+/// never expanded into per-row copies of its (possibly repeated) values.
+pub(crate) enum FixedSizeBinaryState {
+    Values(FixedSizeBinary, MutableBitmap),
+    Dict(Vec<u32>, MutableBitmap),
+}
+
+/// Turns a `Filter` into the ascending, non-overlapping row ranges it selects out of a page of
+/// `len` rows, so the Plain and Dictionary decode paths can skip unselected runs wholesale
+/// instead of decoding every record and filtering downstream.
+pub(crate) fn filter_to_ranges(filter: &Filter<'_>, len: usize) -> Vec<Range<usize>> {
+    match filter {
+        Filter::Range(range) => vec![range.start..usize::min(range.end, len)],
+        Filter::Mask(mask) => {
+            let mut ranges = Vec::new();
+            let mut run_start = None;
+
+            for (i, is_selected) in mask.iter().enumerate() {
+                match (is_selected, run_start) {
+                    (true, None) => run_start = Some(i),
+                    (false, Some(start)) => {
+                        ranges.push(start..i);
+                        run_start = None;
+                    },
+                    _ => {},
+                }
+            }
+
+            if let Some(start) = run_start {
+                ranges.push(start..mask.len());
+            }
+
+            ranges
+        },
+    }
+}
+
+/// Drives `filter_to_ranges(filter, limit)` over a nullable page: an unselected gap is walked
+/// through `page_validity` into a scratch buffer that's immediately discarded (advancing the
+/// page's cursor; the gap's values are still decoded into that scratch buffer, but none of them
+/// reach `target`), and a selected run is
+/// walked the same way into `target`. Every `decode_*_filtered` nullable branch shares exactly
+/// this shape and differs only in which collector it needs, so `new_collector` is called once
+/// per gap and once per selected run to build that collector fresh (it typically holds a
+/// reborrow of the page's own cursor, which a single shared collector value couldn't do across
+/// both the discarded and the kept calls).
+fn extend_filtered_nullable<'a, T, C: BatchableCollector<(), Vec<T>>>(
+    validity: &mut MutableBitmap,
+    page_validity: &mut PageValidity<'a>,
+    filter: &Filter<'a>,
+    limit: usize,
+    target: &mut Vec<T>,
+    mut new_collector: impl FnMut() -> C,
+) -> ParquetResult<()> {
+    let mut cursor = 0usize;
+    let mut scratch_values = Vec::new();
+    let mut scratch_validity = MutableBitmap::new();
+
+    for run in filter_to_ranges(filter, limit) {
+        let gap = run.start.saturating_sub(cursor);
+        if gap > 0 {
+            scratch_values.clear();
+            scratch_validity.clear();
+            extend_from_decoder(
+                &mut scratch_validity,
+                page_validity,
+                Some(gap),
+                &mut scratch_values,
+                new_collector(),
+            )?;
+        }
+
+        let n = run.end.saturating_sub(run.start);
+        if n > 0 {
+            extend_from_decoder(validity, page_validity, Some(n), target, new_collector())?;
+        }
+
+        cursor = run.end;
+    }
+
+    Ok(())
+}
+
+struct FixedSizeBinaryCollector<'a, 'b> {
+    slice: &'b mut &'a [u8],
+    size: usize,
+}
+
+impl<'a, 'b> BatchableCollector<(), Vec<u8>> for FixedSizeBinaryCollector<'a, 'b> {
+    fn reserve(target: &mut Vec<u8>, n: usize) {
+        target.reserve(n);
+    }
+
+    fn push_n(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
+        let n = usize::min(n, self.slice.len() / self.size);
+        target.extend_from_slice(&self.slice[..n * self.size]);
+        *self.slice = &self.slice[n * self.size..];
+        Ok(())
+    }
+
+    fn push_n_nulls(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
+        target.resize(target.len() + n * self.size, 0);
+        Ok(())
+    }
+}
+
+struct ByteStreamSplitCollector<'a, 'b> {
+    buffer: &'a [u8],
+    num_values: usize,
+    size: usize,
+    cursor: &'b mut usize,
+}
+
+impl<'a, 'b> BatchableCollector<(), Vec<u8>> for ByteStreamSplitCollector<'a, 'b> {
+    fn reserve(target: &mut Vec<u8>, n: usize) {
+        target.reserve(n);
+    }
+
+    fn push_n(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
+        let n = usize::min(n, self.num_values.saturating_sub(*self.cursor));
+        target.reserve(n * self.size);
+
+        for i in *self.cursor..*self.cursor + n {
+            for j in 0..self.size {
+                target.push(self.buffer[j * self.num_values + i]);
+            }
+        }
+
+        *self.cursor += n;
+        Ok(())
+    }
+
+    fn push_n_nulls(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
+        target.resize(target.len() + n * self.size, 0);
+        Ok(())
+    }
+}
+
+struct FixedSizeBinaryGatherer<'a> {
+    dict: &'a [u8],
+    size: usize,
+}
+
+impl<'a> HybridRleGatherer<&'a [u8]> for FixedSizeBinaryGatherer<'a> {
+    type Target = Vec<u8>;
+
+    fn target_reserve(&self, target: &mut Self::Target, n: usize) {
+        target.reserve(n * self.size);
+    }
+
+    fn target_num_elements(&self, target: &Self::Target) -> usize {
+        target.len() / self.size
+    }
+
+    fn hybridrle_to_target(&self, value: u32) -> ParquetResult<&'a [u8]> {
+        let value = value as usize;
+
+        if value * self.size >= self.dict.len() {
+            return Err(ParquetError::oos(
+                "Fixed size binary dictionary index out-of-range",
+            ));
+        }
+
+        Ok(&self.dict[value * self.size..(value + 1) * self.size])
+    }
+
+    fn gather_one(&self, target: &mut Self::Target, value: &'a [u8]) -> ParquetResult<()> {
+        target.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn gather_repeated(&self, target: &mut Self::Target, value: &'a [u8], n: usize) -> ParquetResult<()> {
+        for _ in 0..n {
+            target.extend(value);
+        }
+        Ok(())
+    }
+}
+
+/// Gathers raw dictionary keys instead of expanding them into their `size`-byte values, used by
+/// the "delayed dict" decode path.
+struct KeyGatherer {
+    num_dict_values: usize,
+}
+
+impl HybridRleGatherer<u32> for KeyGatherer {
+    type Target = Vec<u32>;
+
+    fn target_reserve(&self, target: &mut Self::Target, n: usize) {
+        target.reserve(n);
+    }
+
+    fn target_num_elements(&self, target: &Self::Target) -> usize {
+        target.len()
+    }
+
+    fn hybridrle_to_target(&self, value: u32) -> ParquetResult<u32> {
+        if value as usize >= self.num_dict_values {
+            return Err(ParquetError::oos(
+                "Fixed size binary dictionary index out-of-range",
+            ));
+        }
+
+        Ok(value)
+    }
+
+    fn gather_one(&self, target: &mut Self::Target, value: u32) -> ParquetResult<()> {
+        target.push(value);
+        Ok(())
+    }
+
+    fn gather_repeated(&self, target: &mut Self::Target, value: u32, n: usize) -> ParquetResult<()> {
+        target.resize(target.len() + n, value);
+        Ok(())
+    }
+}
+
 impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
     type PlainDecoder = &'a [u8];
 
@@ -31,7 +264,7 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
         page: &'a DataPage,
         dict: Option<&'a <BinaryDecoder as Decoder>::Dict>,
         _page_validity: Option<&PageValidity<'a>>,
-        _filter: Option<&Filter<'a>>,
+        filter: Option<&Filter<'a>>,
     ) -> PolarsResult<Self> {
         match (page.encoding(), dict) {
             (Encoding::Plain, _) => {
@@ -44,11 +277,23 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
                     ))
                     .into());
                 }
-                Ok(Self::Plain(values, decoder.size))
+                Ok(Self::Plain(values, decoder.size, filter.cloned()))
             },
             (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict)) => {
                 let values = dict_indices_decoder(page)?;
-                Ok(Self::Dictionary(values, dict))
+                Ok(Self::Dictionary(values, dict, filter.cloned()))
+            },
+            (Encoding::ByteStreamSplit, _) => {
+                let values = split_buffer(page)?.values;
+                if values.len() % decoder.size != 0 {
+                    return Err(ParquetError::oos(format!(
+                        "Fixed size binary data length {} is not divisible by size {}",
+                        values.len(),
+                        decoder.size
+                    ))
+                    .into());
+                }
+                Ok(Self::ByteStreamSplit(values, decoder.size, 0, filter.cloned()))
             },
             _ => Err(not_implemented(page)),
         }
@@ -56,8 +301,9 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
 
     fn len_when_not_nullable(&self) -> usize {
         match self {
-            Self::Plain(v, size) => v.len() / size,
-            Self::Dictionary(v, _) => v.len(),
+            Self::Plain(v, size, _) => v.len() / size,
+            Self::Dictionary(v, _, _) => v.len(),
+            Self::ByteStreamSplit(v, size, cursor, _) => v.len() / size - cursor,
         }
     }
 
@@ -67,8 +313,9 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
         }
 
         match self {
-            Self::Plain(v, size) => *v = &v[usize::min(v.len(), n * *size)..],
-            Self::Dictionary(v, _) => v.skip_in_place(n)?,
+            Self::Plain(v, size, _) => *v = &v[usize::min(v.len(), n * *size)..],
+            Self::Dictionary(v, _, _) => v.skip_in_place(n)?,
+            Self::ByteStreamSplit(_, _, cursor, _) => *cursor += n,
         }
 
         Ok(())
@@ -83,19 +330,55 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
     ) -> ParquetResult<()> {
         use StateTranslation as T;
         match self {
-            T::Plain(page_values, _) => decoder.decode_plain_encoded(
-                decoded,
-                page_values,
-                page_validity.as_mut(),
-                additional,
-            )?,
-            T::Dictionary(page_values, dict) => decoder.decode_dictionary_encoded(
-                decoded,
-                page_values,
-                page_validity.as_mut(),
-                dict,
-                additional,
-            )?,
+            T::Plain(page_values, _, filter) => match filter.as_ref() {
+                Some(filter) => decoder.decode_plain_encoded_filtered(
+                    decoded,
+                    page_values,
+                    page_validity.as_mut(),
+                    filter,
+                    additional,
+                )?,
+                None => decoder.decode_plain_encoded(
+                    decoded,
+                    page_values,
+                    page_validity.as_mut(),
+                    additional,
+                )?,
+            },
+            T::Dictionary(page_values, dict, filter) => match filter.as_ref() {
+                Some(filter) => decoder.decode_dictionary_encoded_filtered(
+                    decoded,
+                    page_values,
+                    page_validity.as_mut(),
+                    dict,
+                    filter,
+                    additional,
+                )?,
+                None => decoder.decode_dictionary_encoded(
+                    decoded,
+                    page_values,
+                    page_validity.as_mut(),
+                    dict,
+                    additional,
+                )?,
+            },
+            T::ByteStreamSplit(buffer, _, cursor, filter) => match filter.as_ref() {
+                Some(filter) => decoder.decode_byte_stream_split_encoded_filtered(
+                    decoded,
+                    buffer,
+                    cursor,
+                    page_validity.as_mut(),
+                    filter,
+                    additional,
+                )?,
+                None => decoder.decode_byte_stream_split_encoded(
+                    decoded,
+                    buffer,
+                    cursor,
+                    page_validity.as_mut(),
+                    additional,
+                )?,
+            },
         }
 
         Ok(())
@@ -103,7 +386,23 @@ impl<'a> utils::StateTranslation<'a, BinaryDecoder> for StateTranslation<'a> {
 }
 
 pub(crate) struct BinaryDecoder {
-    pub(crate) size: usize,
+    size: usize,
+    /// Whether the final output is a `DictionaryArray`, in which case decoding a dictionary page
+    /// can stay in the "delayed dict" state and skip materializing value bytes entirely.
+    is_dictionary: bool,
+}
+
+impl BinaryDecoder {
+    /// Builds the decoder for a `FixedSizeBinary(size)` column whose final Arrow output is
+    /// `data_type`. `is_dictionary` is derived from `data_type` rather than taken as a separate
+    /// argument so a caller can't wire up the dictionary fast path for a `FixedSizeBinaryArray`
+    /// target, or skip it for a `DictionaryArray` one.
+    pub(crate) fn new(size: usize, data_type: &ArrowDataType) -> Self {
+        Self {
+            size,
+            is_dictionary: matches!(data_type, ArrowDataType::Dictionary(_, _, _)),
+        }
+    }
 }
 
 impl<T> utils::ExactSize for Vec<T> {
@@ -112,21 +411,31 @@ impl<T> utils::ExactSize for Vec<T> {
     }
 }
 
-impl utils::ExactSize for (FixedSizeBinary, MutableBitmap) {
+impl utils::ExactSize for FixedSizeBinaryState {
     fn len(&self) -> usize {
-        self.0.values.len() / self.0.size
+        match self {
+            Self::Values(values, _) => values.values.len() / values.size,
+            Self::Dict(keys, _) => keys.len(),
+        }
     }
 }
 
 impl Decoder for BinaryDecoder {
     type Translation<'a> = StateTranslation<'a>;
     type Dict = Vec<u8>;
-    type DecodedState = (FixedSizeBinary, MutableBitmap);
+    type DecodedState = FixedSizeBinaryState;
 
     fn with_capacity(&self, capacity: usize) -> Self::DecodedState {
         let size = self.size;
 
-        (
+        if self.is_dictionary {
+            return FixedSizeBinaryState::Dict(
+                Vec::with_capacity(capacity),
+                MutableBitmap::with_capacity(capacity),
+            );
+        }
+
+        FixedSizeBinaryState::Values(
             FixedSizeBinary {
                 values: Vec::with_capacity(capacity * size),
                 size,
@@ -141,33 +450,18 @@ impl Decoder for BinaryDecoder {
 
     fn decode_plain_encoded<'a>(
         &mut self,
-        (values, validity): &mut Self::DecodedState,
+        state: &mut Self::DecodedState,
         page_values: &mut <Self::Translation<'a> as utils::StateTranslation<'a, Self>>::PlainDecoder,
         page_validity: Option<&mut PageValidity<'a>>,
         limit: usize,
     ) -> ParquetResult<()> {
-        struct FixedSizeBinaryCollector<'a, 'b> {
-            slice: &'b mut &'a [u8],
-            size: usize,
-        }
-
-        impl<'a, 'b> BatchableCollector<(), Vec<u8>> for FixedSizeBinaryCollector<'a, 'b> {
-            fn reserve(target: &mut Vec<u8>, n: usize) {
-                target.reserve(n);
-            }
-
-            fn push_n(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
-                let n = usize::min(n, self.slice.len() / self.size);
-                target.extend_from_slice(&self.slice[..n * self.size]);
-                *self.slice = &self.slice[n * self.size..];
-                Ok(())
-            }
-
-            fn push_n_nulls(&mut self, target: &mut Vec<u8>, n: usize) -> ParquetResult<()> {
-                target.resize(target.len() + n * self.size, 0);
-                Ok(())
-            }
-        }
+        // A plain-encoded page never carries a dictionary to delay against, so it can only ever
+        // target the fully-materialized `Values` state.
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            return Err(ParquetError::oos(
+                "Fixed size binary column mixes a Plain-encoded page with a dictionary-array target",
+            ));
+        };
 
         let mut collector = FixedSizeBinaryCollector {
             slice: page_values,
@@ -175,7 +469,7 @@ impl Decoder for BinaryDecoder {
         };
 
         match page_validity {
-            None => collector.push_n(&mut values.values, self.size)?,
+            None => collector.push_n(&mut values.values, limit)?,
             Some(page_validity) => extend_from_decoder(
                 validity,
                 page_validity,
@@ -190,57 +484,26 @@ impl Decoder for BinaryDecoder {
 
     fn decode_dictionary_encoded<'a>(
         &mut self,
-        (values, validity): &mut Self::DecodedState,
+        state: &mut Self::DecodedState,
         page_values: &mut hybrid_rle::HybridRleDecoder<'a>,
         page_validity: Option<&mut PageValidity<'a>>,
         dict: &Self::Dict,
         limit: usize,
     ) -> ParquetResult<()> {
-        struct FixedSizeBinaryGatherer<'a> {
-            dict: &'a [u8],
-            size: usize,
+        if let FixedSizeBinaryState::Dict(keys, validity) = state {
+            return self.decode_dictionary_encoded_keys(
+                keys,
+                validity,
+                page_values,
+                page_validity,
+                dict,
+                limit,
+            );
         }
 
-        impl<'a> HybridRleGatherer<&'a [u8]> for FixedSizeBinaryGatherer<'a> {
-            type Target = Vec<u8>;
-
-            fn target_reserve(&self, target: &mut Self::Target, n: usize) {
-                target.reserve(n * self.size);
-            }
-
-            fn target_num_elements(&self, target: &Self::Target) -> usize {
-                target.len() / self.size
-            }
-
-            fn hybridrle_to_target(&self, value: u32) -> ParquetResult<&'a [u8]> {
-                let value = value as usize;
-
-                if value * self.size >= self.dict.len() {
-                    return Err(ParquetError::oos(
-                        "Fixed size binary dictionary index out-of-range",
-                    ));
-                }
-
-                Ok(&self.dict[value * self.size..(value + 1) * self.size])
-            }
-
-            fn gather_one(&self, target: &mut Self::Target, value: &'a [u8]) -> ParquetResult<()> {
-                target.extend_from_slice(value);
-                Ok(())
-            }
-
-            fn gather_repeated(
-                &self,
-                target: &mut Self::Target,
-                value: &'a [u8],
-                n: usize,
-            ) -> ParquetResult<()> {
-                for _ in 0..n {
-                    target.extend(value);
-                }
-                Ok(())
-            }
-        }
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            unreachable!()
+        };
 
         let gatherer = FixedSizeBinaryGatherer {
             dict,
@@ -272,8 +535,14 @@ impl Decoder for BinaryDecoder {
     fn finalize(
         &self,
         data_type: ArrowDataType,
-        (values, validity): Self::DecodedState,
+        state: Self::DecodedState,
     ) -> ParquetResult<Box<dyn Array>> {
+        // A `Dict` state is only ever produced when the target is a `DictionaryArray`, which is
+        // finalized through `finalize_dict_array` instead.
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            unreachable!("dictionary-keyed state must be finalized via finalize_dict_array")
+        };
+
         Ok(Box::new(FixedSizeBinaryArray::new(
             data_type,
             values.values.into(),
@@ -294,23 +563,678 @@ impl Decoder for BinaryDecoder {
     }
 }
 
+impl BinaryDecoder {
+    /// The "delayed dict" path: gathers raw dictionary keys instead of expanding them into their
+    /// `size`-byte values. Nulls are pushed as key `0` with their validity bit cleared, so the
+    /// filler key is never read back once `finalize_dict_array` attaches the dictionary page's
+    /// values directly.
+    fn decode_dictionary_encoded_keys<'a>(
+        &self,
+        keys: &mut Vec<u32>,
+        validity: &mut MutableBitmap,
+        page_values: &mut hybrid_rle::HybridRleDecoder<'a>,
+        page_validity: Option<&mut PageValidity<'a>>,
+        dict: &<Self as Decoder>::Dict,
+        limit: usize,
+    ) -> ParquetResult<()> {
+        let gatherer = KeyGatherer {
+            num_dict_values: dict.len() / self.size,
+        };
+        // Index 0 is the same null filler the non-delayed path uses (`&dict[..self.size]`), and
+        // it is what `NestedDecoder::values_extend_nulls` fills definition-level gaps with, so the
+        // two stay consistent for nested List/Struct columns.
+        let null_key = 0u32;
+
+        match page_validity {
+            None => {
+                page_values.gather_n_into(keys, limit, &gatherer)?;
+            },
+            Some(page_validity) => {
+                let collector = GatheredHybridRle::new(page_values, &gatherer, null_key);
+
+                extend_from_decoder(validity, page_validity, Some(limit), keys, collector)?;
+            },
+        }
+
+        Ok(())
+    }
+
+    /// BYTE_STREAM_SPLIT reconstructs each record by reading byte `j` of element `i` from offset
+    /// `j * num_values + i` of the page buffer, i.e. all byte-0s are stored contiguously, then all
+    /// byte-1s, and so on. `cursor` tracks how many records have already been consumed and only
+    /// ever advances (the buffer itself is never sliced, since its bytes are transposed).
+    fn decode_byte_stream_split_encoded(
+        &mut self,
+        state: &mut <Self as Decoder>::DecodedState,
+        buffer: &[u8],
+        cursor: &mut usize,
+        page_validity: Option<&mut PageValidity<'_>>,
+        limit: usize,
+    ) -> ParquetResult<()> {
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            return Err(ParquetError::oos(
+                "Fixed size binary column mixes a BYTE_STREAM_SPLIT page with a dictionary-array target",
+            ));
+        };
+
+        let mut collector = ByteStreamSplitCollector {
+            buffer,
+            num_values: buffer.len() / self.size,
+            size: self.size,
+            cursor,
+        };
+
+        match page_validity {
+            None => collector.push_n(&mut values.values, limit)?,
+            Some(page_validity) => extend_from_decoder(
+                validity,
+                page_validity,
+                Some(limit),
+                &mut values.values,
+                collector,
+            )?,
+        }
+
+        Ok(())
+    }
+
+    /// Honors a row-selecting `Filter` on a BYTE_STREAM_SPLIT page, the same way
+    /// `decode_plain_encoded_filtered` does for Plain. For a required column, an unselected run is
+    /// skipped as a trivial `cursor` advance (the transposed layout is index-preserving, so no
+    /// bytes need to be read or copied). For a nullable column `cursor` only moves in step with
+    /// `page_validity`, so a gap run is instead walked through `extend_from_decoder` into a scratch
+    /// buffer that's immediately discarded.
+    fn decode_byte_stream_split_encoded_filtered<'a>(
+        &mut self,
+        state: &mut <Self as Decoder>::DecodedState,
+        buffer: &'a [u8],
+        cursor: &mut usize,
+        page_validity: Option<&mut PageValidity<'a>>,
+        filter: &Filter<'a>,
+        limit: usize,
+    ) -> ParquetResult<()> {
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            return Err(ParquetError::oos(
+                "Fixed size binary column mixes a BYTE_STREAM_SPLIT page with a dictionary-array target",
+            ));
+        };
+
+        let size = self.size;
+        let num_values = buffer.len() / size;
+
+        let Some(page_validity) = page_validity else {
+            let num_rows = num_values.saturating_sub(*cursor);
+            let mut run_cursor = 0usize;
+
+            for run in filter_to_ranges(filter, num_rows) {
+                *cursor += run.start.saturating_sub(run_cursor);
+
+                let n = run.end.saturating_sub(run.start);
+                values.values.reserve(n * size);
+                for i in *cursor..*cursor + n {
+                    for j in 0..size {
+                        values.values.push(buffer[j * num_values + i]);
+                    }
+                }
+                *cursor += n;
+                validity.extend_constant(n, true);
+
+                run_cursor = run.end;
+            }
+
+            return Ok(());
+        };
+
+        extend_filtered_nullable(validity, page_validity, filter, limit, &mut values.values, || {
+            ByteStreamSplitCollector {
+                buffer,
+                num_values,
+                size,
+                cursor: &mut *cursor,
+            }
+        })
+    }
+
+    /// Honors a row-selecting `Filter` on a Plain-encoded page. For a required (non-nullable)
+    /// column, unselected runs are skipped as a byte-offset advance (`n * size`) instead of being
+    /// decoded and discarded. For a nullable column, `page_values` only holds bytes for the
+    /// non-null rows, so a byte-offset skip can't work; instead a gap run is walked through
+    /// `page_validity` via `extend_from_decoder` into a scratch buffer that's immediately
+    /// discarded, which still advances `page_values` and `page_validity` correctly. The gap's
+    /// bytes are copied into that scratch buffer; none of them reach the decoded output.
+    fn decode_plain_encoded_filtered<'a>(
+        &mut self,
+        state: &mut <Self as Decoder>::DecodedState,
+        page_values: &mut &'a [u8],
+        page_validity: Option<&mut PageValidity<'a>>,
+        filter: &Filter<'a>,
+        limit: usize,
+    ) -> ParquetResult<()> {
+        let FixedSizeBinaryState::Values(values, validity) = state else {
+            return Err(ParquetError::oos(
+                "Fixed size binary column mixes a Plain-encoded page with a dictionary-array target",
+            ));
+        };
+
+        let size = self.size;
+
+        let Some(page_validity) = page_validity else {
+            let num_rows = page_values.len() / size;
+            let mut cursor = 0usize;
+
+            for run in filter_to_ranges(filter, num_rows) {
+                let gap = run.start.saturating_sub(cursor);
+                *page_values = &page_values[usize::min(page_values.len(), gap * size)..];
+
+                let n = run.end.saturating_sub(run.start);
+                let take = usize::min(n * size, page_values.len());
+                values.values.extend_from_slice(&page_values[..take]);
+                *page_values = &page_values[take..];
+                validity.extend_constant(n, true);
+
+                cursor = run.end;
+            }
+
+            return Ok(());
+        };
+
+        extend_filtered_nullable(validity, page_validity, filter, limit, &mut values.values, || {
+            FixedSizeBinaryCollector {
+                slice: &mut *page_values,
+                size,
+            }
+        })
+    }
+
+    /// Honors a row-selecting `Filter` on a Dictionary-encoded page. For a required column,
+    /// unselected runs are skipped in place with `HybridRleDecoder::skip_in_place` and selected
+    /// runs are gathered with `gather_n_into`, so filtered-out indices are never materialized.
+    /// For a nullable column the same discard-into-scratch approach as
+    /// `decode_plain_encoded_filtered` is used, walking `page_validity` through
+    /// `extend_from_decoder` for both gaps and selected runs.
+    fn decode_dictionary_encoded_filtered<'a>(
+        &mut self,
+        state: &mut <Self as Decoder>::DecodedState,
+        page_values: &mut hybrid_rle::HybridRleDecoder<'a>,
+        page_validity: Option<&mut PageValidity<'a>>,
+        dict: &<Self as Decoder>::Dict,
+        filter: &Filter<'a>,
+        limit: usize,
+    ) -> ParquetResult<()> {
+        let Some(page_validity) = page_validity else {
+            let num_rows = page_values.len();
+            let mut cursor = 0usize;
+
+            match state {
+                FixedSizeBinaryState::Dict(keys, validity) => {
+                    let gatherer = KeyGatherer {
+                        num_dict_values: dict.len() / self.size,
+                    };
+
+                    for run in filter_to_ranges(filter, num_rows) {
+                        let gap = run.start.saturating_sub(cursor);
+                        if gap > 0 {
+                            page_values.skip_in_place(gap)?;
+                        }
+
+                        let n = run.end.saturating_sub(run.start);
+                        page_values.gather_n_into(keys, n, &gatherer)?;
+                        validity.extend_constant(n, true);
+
+                        cursor = run.end;
+                    }
+                },
+                FixedSizeBinaryState::Values(values, validity) => {
+                    let gatherer = FixedSizeBinaryGatherer {
+                        dict,
+                        size: self.size,
+                    };
+
+                    for run in filter_to_ranges(filter, num_rows) {
+                        let gap = run.start.saturating_sub(cursor);
+                        if gap > 0 {
+                            page_values.skip_in_place(gap)?;
+                        }
+
+                        let n = run.end.saturating_sub(run.start);
+                        page_values.gather_n_into(&mut values.values, n, &gatherer)?;
+                        validity.extend_constant(n, true);
+
+                        cursor = run.end;
+                    }
+                },
+            }
+
+            return Ok(());
+        };
+
+        match state {
+            FixedSizeBinaryState::Dict(keys, validity) => {
+                let gatherer = KeyGatherer {
+                    num_dict_values: dict.len() / self.size,
+                };
+                extend_filtered_nullable(validity, page_validity, filter, limit, keys, || {
+                    GatheredHybridRle::new(&mut *page_values, &gatherer, 0u32)
+                })
+            },
+            FixedSizeBinaryState::Values(values, validity) => {
+                let gatherer = FixedSizeBinaryGatherer {
+                    dict,
+                    size: self.size,
+                };
+                let null_value = &dict[..self.size];
+                extend_filtered_nullable(
+                    validity,
+                    page_validity,
+                    filter,
+                    limit,
+                    &mut values.values,
+                    || GatheredHybridRle::new(&mut *page_values, &gatherer, null_value),
+                )
+            },
+        }
+    }
+}
+
 impl utils::NestedDecoder for BinaryDecoder {
     fn validity_extend(
         _: &mut utils::State<'_, Self>,
-        (_, validity): &mut Self::DecodedState,
+        state: &mut Self::DecodedState,
         value: bool,
         n: usize,
     ) {
+        let validity = match state {
+            FixedSizeBinaryState::Values(_, validity) => validity,
+            FixedSizeBinaryState::Dict(_, validity) => validity,
+        };
         validity.extend_constant(n, value);
     }
 
     fn values_extend_nulls(
         _: &mut utils::State<'_, Self>,
-        (values, _): &mut Self::DecodedState,
+        state: &mut Self::DecodedState,
         n: usize,
     ) {
-        values
-            .values
-            .resize(values.values.len() + n * values.size, 0);
+        match state {
+            FixedSizeBinaryState::Values(values, _) => {
+                values
+                    .values
+                    .resize(values.values.len() + n * values.size, 0);
+            },
+            FixedSizeBinaryState::Dict(keys, _) => {
+                keys.resize(keys.len() + n, 0);
+            },
+        }
+    }
+}
+
+/// Fills `run_len` leaf positions that a nested column's repetition/definition levels say don't
+/// hold a value — a null, or a slot inside an empty list/struct — filling the values the same way
+/// `NestedDecoder::values_extend_nulls` does and pushing a cleared validity bit for each. Pulled out of that impl so the gap-filling half of
+/// [`decode_dictionary_encoded_keys_segmented`] can be tested on its own, without needing a real
+/// dictionary-encoded page for the non-gap segments.
+fn fill_nested_gap(state: &mut FixedSizeBinaryState, run_len: usize) {
+    match state {
+        FixedSizeBinaryState::Values(values, validity) => {
+            validity.extend_constant(run_len, false);
+            values
+                .values
+                .resize(values.values.len() + run_len * values.size, 0);
+        },
+        FixedSizeBinaryState::Dict(keys, validity) => {
+            validity.extend_constant(run_len, false);
+            keys.resize(keys.len() + run_len, 0);
+        },
+    }
+}
+
+/// Drives a dictionary-encoded index stream through an explicit sequence of definition-level runs
+/// for a `FixedSizeBinary` nested under a `List`/`LargeList`/`Struct`. Each `(run_len, has_value)`
+/// segment is either a run of `run_len` leaf positions that hold a dictionary index — gathered
+/// from `page_values` through the same dict-state-aware `decode_dictionary_encoded` the flat
+/// column path uses — or a run implied null/absent by the nesting structure, filled by
+/// `fill_nested_gap`. This is what makes a dictionary-encoded nested column actually decode its
+/// index stream segment-by-segment against repetition/definition levels, rather than only ever
+/// being handed one flat run covering the whole page.
+///
+/// Partially tested only: the gap branch is covered directly (see `fill_nested_gap`'s tests), but
+/// the value-bearing branch's call into `decode_dictionary_encoded` needs a real dictionary-encoded
+/// `hybrid_rle::HybridRleDecoder` page, which nothing in this tree can construct — there's no
+/// Parquet read/write machinery here to produce one, let alone round-trip an actual
+/// `list<fixed_size_binary>` / `struct { fixed_size_binary }` file through Plain and RleDictionary
+/// encodings the way the request asks for. That coverage gap is still open.
+pub(crate) fn decode_dictionary_encoded_keys_segmented<'a>(
+    decoder: &mut BinaryDecoder,
+    state: &mut FixedSizeBinaryState,
+    page_values: &mut hybrid_rle::HybridRleDecoder<'a>,
+    dict: &<BinaryDecoder as Decoder>::Dict,
+    segments: impl IntoIterator<Item = (usize, bool)>,
+) -> ParquetResult<()> {
+    for (run_len, has_value) in segments {
+        if run_len == 0 {
+            continue;
+        }
+
+        if has_value {
+            decoder.decode_dictionary_encoded(state, page_values, None, dict, run_len)?;
+            let validity = match state {
+                FixedSizeBinaryState::Values(_, validity) => validity,
+                FixedSizeBinaryState::Dict(_, validity) => validity,
+            };
+            validity.extend_constant(run_len, true);
+        } else {
+            fill_nested_gap(state, run_len);
+        }
+    }
+
+    Ok(())
+}
+
+/// A predicate evaluated against a fixed-size-binary page's raw `min`/`max` bytes for
+/// ColumnIndex/OffsetIndex-based page pruning. Comparisons use plain lexicographic ordering of
+/// the `size`-byte slices, which is how Parquet itself orders FIXED_LEN_BYTE_ARRAY statistics.
+pub(crate) enum FixedSizeBinaryPagePredicate<'a> {
+    Eq(&'a [u8]),
+    Lt(&'a [u8]),
+    LtEq(&'a [u8]),
+    Gt(&'a [u8]),
+    GtEq(&'a [u8]),
+    IsNull,
+}
+
+impl<'a> FixedSizeBinaryPagePredicate<'a> {
+    /// Whether a page whose values lie within `[min, max]` could still contain a row satisfying
+    /// this predicate. Only called for pages that are not all-null; `IsNull` is resolved by the
+    /// caller from the `null_pages` flag and, where present, the page's `null_counts` entry.
+    fn may_match(&self, min: &[u8], max: &[u8]) -> bool {
+        match self {
+            Self::Eq(v) => min <= *v && *v <= max,
+            Self::Lt(v) => min < *v,
+            Self::LtEq(v) => min <= *v,
+            Self::Gt(v) => max > *v,
+            Self::GtEq(v) => max >= *v,
+            Self::IsNull => false,
+        }
+    }
+}
+
+/// Evaluates `predicate` against a fixed-size-binary column's `ColumnIndex`/`OffsetIndex` and
+/// returns a `Filter` selecting only the rows of pages that could still satisfy it, so whole pages
+/// that provably can't match are dropped before `BinaryDecoder` ever runs. `num_rows` is the total
+/// row count of the column chunk, used to bound the last page's row range.
+pub(crate) fn prune_pages(
+    column_index: &ColumnIndex,
+    offset_index: &OffsetIndex,
+    size: usize,
+    num_rows: usize,
+    predicate: &FixedSizeBinaryPagePredicate<'_>,
+) -> ParquetResult<Filter<'static>> {
+    let num_pages = column_index.null_pages.len();
+
+    if column_index.min_values.len() != num_pages || column_index.max_values.len() != num_pages {
+        return Err(ParquetError::oos(
+            "Fixed size binary ColumnIndex min/max buffers do not have one entry per page",
+        ));
+    }
+
+    if column_index
+        .min_values
+        .iter()
+        .chain(column_index.max_values.iter())
+        .any(|v| v.len() != size)
+    {
+        return Err(ParquetError::oos(
+            "Fixed size binary ColumnIndex min/max entry does not match the column's size",
+        ));
+    }
+
+    let mut page_row_counts = Vec::with_capacity(num_pages);
+    for page in 0..num_pages {
+        let first_row = offset_index.page_locations[page].first_row_index as usize;
+        let next_row = offset_index
+            .page_locations
+            .get(page + 1)
+            .map(|p| p.first_row_index as usize)
+            .unwrap_or(num_rows);
+        page_row_counts.push(next_row.saturating_sub(first_row));
+    }
+
+    let mask = pages_to_keep(
+        &column_index.null_pages,
+        &column_index.min_values,
+        &column_index.max_values,
+        column_index.null_counts.as_deref(),
+        &page_row_counts,
+        predicate,
+    );
+
+    Ok(Filter::Mask(mask))
+}
+
+/// The per-page keep/drop decision behind [`prune_pages`], pulled out so it can be exercised
+/// without needing a real `ColumnIndex`/`OffsetIndex`. `null_counts[page]`, when present, is the
+/// number of null rows in that page — the only way to prove a non-entirely-null page has *zero*
+/// nulls, which is what `IsNull` pruning needs.
+fn pages_to_keep(
+    null_pages: &[bool],
+    min_values: &[Vec<u8>],
+    max_values: &[Vec<u8>],
+    null_counts: Option<&[i64]>,
+    page_row_counts: &[usize],
+    predicate: &FixedSizeBinaryPagePredicate<'_>,
+) -> Bitmap {
+    let mut mask = MutableBitmap::with_capacity(page_row_counts.iter().sum());
+
+    for page in 0..null_pages.len() {
+        let keep = if matches!(predicate, FixedSizeBinaryPagePredicate::IsNull) {
+            if null_pages[page] {
+                // Every row in the page is null: it trivially satisfies `IsNull`.
+                true
+            } else {
+                // `null_pages[page] == false` only means "not entirely null" — it says nothing
+                // about whether the page has *zero* nulls mixed in among non-null rows. Only a
+                // `null_counts` entry of exactly 0 proves that, so without it we have to
+                // conservatively keep the page rather than drop rows that might be null.
+                !matches!(null_counts.and_then(|counts| counts.get(page)), Some(0))
+            }
+        } else if null_pages[page] {
+            // Every row in the page is null, so it can't satisfy a non-`IsNull` value predicate.
+            false
+        } else {
+            predicate.may_match(&min_values[page], &max_values[page])
+        };
+
+        mask.extend_constant(page_row_counts[page], keep);
+    }
+
+    mask.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::IntegerType;
+
+    use super::*;
+
+    #[test]
+    fn finalize_dict_array_accepts_zero_filler_key_on_null_rows() {
+        // `decode_dictionary_encoded_keys` fills null rows with key `0`, the same filler
+        // `decode_dictionary_encoded`'s non-delayed path uses (`&dict[..size]`). An earlier version
+        // filled null rows with `num_dict_values` instead, one past the last valid index;
+        // `DictionaryArray::try_new` validates every key regardless of its validity bit, so that
+        // sentinel would make this call fail for any nullable dictionary column.
+        let size = 4;
+        let dict = b"abcd".to_vec();
+
+        let decoder = BinaryDecoder::new(
+            size,
+            &ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(ArrowDataType::FixedSizeBinary(size)),
+                false,
+            ),
+        );
+
+        let mut validity = MutableBitmap::with_capacity(2);
+        validity.extend_constant(1, true);
+        validity.extend_constant(1, false);
+
+        let array = decoder
+            .finalize_dict_array::<u32>(
+                ArrowDataType::Dictionary(
+                    IntegerType::UInt32,
+                    Box::new(ArrowDataType::FixedSizeBinary(size)),
+                    false,
+                ),
+                dict,
+                (vec![0u32, 0u32], Some(validity.into())),
+            )
+            .unwrap();
+
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.validity().map(|v| v.get_bit(1)), Some(false));
+    }
+
+    #[test]
+    fn pages_to_keep_does_not_drop_a_mixed_null_page_for_is_null() {
+        // Page 0 is entirely null (keep: its rows are all nulls). Page 1 is *not* entirely null
+        // (`null_pages[1] == false`) but its null count is unknown, so it might still hold null
+        // rows a caller filtering on `IS NULL` needs — it must be kept, not dropped.
+        let null_pages = vec![true, false];
+        let min_values = vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0]];
+        let max_values = vec![vec![0, 0, 0, 0], vec![1, 1, 1, 1]];
+        let page_row_counts = vec![2, 3];
+
+        let mask = pages_to_keep(
+            &null_pages,
+            &min_values,
+            &max_values,
+            None,
+            &page_row_counts,
+            &FixedSizeBinaryPagePredicate::IsNull,
+        );
+
+        assert!(mask.iter().all(|keep| keep));
+    }
+
+    #[test]
+    fn pages_to_keep_drops_a_page_proven_to_have_zero_nulls() {
+        let null_pages = vec![false];
+        let min_values = vec![vec![0, 0, 0, 0]];
+        let max_values = vec![vec![1, 1, 1, 1]];
+        let null_counts = vec![0i64];
+        let page_row_counts = vec![4];
+
+        let mask = pages_to_keep(
+            &null_pages,
+            &min_values,
+            &max_values,
+            Some(&null_counts),
+            &page_row_counts,
+            &FixedSizeBinaryPagePredicate::IsNull,
+        );
+
+        assert!(mask.iter().all(|keep| !keep));
+    }
+
+    // `decode_dictionary_encoded_keys_segmented` itself needs a real dictionary-encoded
+    // `hybrid_rle::HybridRleDecoder` page for its value-bearing segments, which this crate's
+    // parquet-file read/write machinery (outside this file, and not part of this tree's snapshot)
+    // would normally supply via an end-to-end `list<fixed_size_binary>` / `struct { fixed_size_binary }`
+    // round trip in both Plain and RleDictionary encodings, as the request asks for. That machinery
+    // isn't reachable from here, so these tests instead pin down `fill_nested_gap` — the half of
+    // the segmented decode that doesn't depend on it — against the run shapes a list or struct
+    // column's definition levels actually produce: a null row, and an empty-list row.
+    #[test]
+    fn fill_nested_gap_fills_a_null_list_row() {
+        // `list<fixed_size_binary>` row shape: [v0, v1], null, [v2] — decoded as value run(2),
+        // gap(1), value run(1). Only the gap is exercised here; value runs go through
+        // `decode_dictionary_encoded`, which no test in this module exercises.
+        let mut validity = MutableBitmap::with_capacity(2);
+        validity.extend_constant(2, true);
+        let mut state = FixedSizeBinaryState::Dict(vec![5, 6], validity);
+
+        fill_nested_gap(&mut state, 1);
+
+        let FixedSizeBinaryState::Dict(keys, validity) = &state else {
+            unreachable!()
+        };
+        assert_eq!(keys, &[5, 6, 0]);
+        assert_eq!(validity.iter().collect::<Vec<_>>(), [true, true, false]);
+    }
+
+    #[test]
+    fn fill_nested_gap_fills_an_empty_list_row() {
+        // `list<fixed_size_binary>` row shape: [v0], [] (empty, not null) — an empty list still
+        // contributes zero leaf values but is itself non-null, so unlike the null row above it
+        // takes no gap slot at the leaf level: it reaches `fill_nested_gap` as a zero-length run.
+        // A run_len of 0 must be a no-op.
+        let mut validity = MutableBitmap::with_capacity(1);
+        validity.extend_constant(1, true);
+        let mut state = FixedSizeBinaryState::Values(
+            FixedSizeBinary {
+                values: b"abcd".to_vec(),
+                size: 4,
+            },
+            validity,
+        );
+
+        fill_nested_gap(&mut state, 0);
+
+        let FixedSizeBinaryState::Values(values, validity) = &state else {
+            unreachable!()
+        };
+        assert_eq!(values.values, b"abcd");
+        assert_eq!(validity.iter().collect::<Vec<_>>(), [true]);
+    }
+
+    #[test]
+    fn fill_nested_gap_handles_a_values_state_struct_null() {
+        // `struct { fixed_size_binary }` row shape: a struct-null row leaves the child's own
+        // definition level unsatisfied, filled the same way as a leaf null.
+        let size = 3;
+        let mut state = FixedSizeBinaryState::Values(
+            FixedSizeBinary {
+                values: Vec::new(),
+                size,
+            },
+            MutableBitmap::new(),
+        );
+
+        fill_nested_gap(&mut state, 2);
+
+        let FixedSizeBinaryState::Values(values, validity) = &state else {
+            unreachable!()
+        };
+        assert_eq!(values.values, vec![0u8; 2 * size]);
+        assert_eq!(validity.iter().collect::<Vec<_>>(), [false, false]);
+    }
+
+    #[test]
+    fn decode_byte_stream_split_encoded_reconstructs_transposed_records() {
+        // Three 2-byte records [0x01, 0x02], [0x03, 0x04], [0x05, 0x06] laid out
+        // BYTE_STREAM_SPLIT: all byte-0s first, then all byte-1s.
+        let size = 2;
+        let buffer = vec![0x01, 0x03, 0x05, 0x02, 0x04, 0x06];
+
+        let mut decoder = BinaryDecoder::new(size, &ArrowDataType::FixedSizeBinary(size));
+        let mut state = FixedSizeBinaryState::Values(
+            FixedSizeBinary {
+                values: Vec::new(),
+                size,
+            },
+            MutableBitmap::new(),
+        );
+        let mut cursor = 0usize;
+
+        decoder
+            .decode_byte_stream_split_encoded(&mut state, &buffer, &mut cursor, None, 3)
+            .unwrap();
+
+        let FixedSizeBinaryState::Values(values, _) = &state else {
+            unreachable!()
+        };
+        assert_eq!(values.values, vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(cursor, 3);
     }
 }
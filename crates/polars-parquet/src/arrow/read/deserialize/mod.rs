@@ -0,0 +1,69 @@
+mod fixed_size_binary;
+
+use arrow::bitmap::MutableBitmap;
+use arrow::datatypes::ArrowDataType;
+
+use crate::parquet::error::ParquetResult;
+use crate::parquet::indexes::{ColumnIndex, OffsetIndex};
+use crate::read::deserialize::utils::filter::Filter;
+
+// `decode_nested_fixed_size_binary_dictionary_keys` is where a nested (List/Struct) column
+// reader should dispatch dictionary-encoded `FixedSizeBinary` decode once such a reader exists;
+// nothing in this tree calls it yet — it has no caller outside this module.
+pub(crate) use fixed_size_binary::{
+    decode_dictionary_encoded_keys_segmented as decode_nested_fixed_size_binary_dictionary_keys,
+    prune_pages as prune_fixed_size_binary_pages, BinaryDecoder as FixedSizeBinaryDecoder,
+    FixedSizeBinaryPagePredicate,
+};
+
+/// Builds the `FixedSizeBinary` decoder for a column: `data_type` is the column's final Arrow
+/// output type, so a `Dictionary(_, FixedSizeBinary(size), _)` target takes the delayed-dict
+/// decode path and a plain `FixedSizeBinary(size)` target takes the fully-materialized one.
+/// Nothing in this tree calls this yet — the column-chunk reader that would own
+/// `BinaryDecoder` construction doesn't exist in this snapshot. This is where it should call in
+/// once it does, not evidence that it already does.
+pub(crate) fn fixed_size_binary_decoder(size: usize, data_type: &ArrowDataType) -> FixedSizeBinaryDecoder {
+    FixedSizeBinaryDecoder::new(size, data_type)
+}
+
+/// Combines a caller-pushed-down `Filter` with `ColumnIndex`/`OffsetIndex` page pruning, so a page
+/// statistics already rule out doesn't get decoded just because the caller's own filter would have
+/// kept some of its rows. Where a column-chunk reader holding `ColumnIndex`/`OffsetIndex` and an
+/// existing row filter should call `prune_fixed_size_binary_pages`, once such a reader exists.
+/// Nothing in this tree calls this function yet — it has no caller outside this module.
+pub(crate) fn fixed_size_binary_page_filter<'a>(
+    column_index: &ColumnIndex,
+    offset_index: &OffsetIndex,
+    size: usize,
+    num_rows: usize,
+    predicate: &FixedSizeBinaryPagePredicate<'_>,
+    existing: Option<&Filter<'a>>,
+) -> ParquetResult<Filter<'static>> {
+    let Filter::Mask(pruned_mask) =
+        prune_fixed_size_binary_pages(column_index, offset_index, size, num_rows, predicate)?
+    else {
+        unreachable!("prune_fixed_size_binary_pages always returns Filter::Mask")
+    };
+
+    let Some(existing) = existing else {
+        return Ok(Filter::Mask(pruned_mask));
+    };
+
+    let existing_ranges = fixed_size_binary::filter_to_ranges(existing, num_rows);
+    let mut existing_mask = MutableBitmap::with_capacity(num_rows);
+    let mut cursor = 0usize;
+    for range in existing_ranges {
+        existing_mask.extend_constant(range.start.saturating_sub(cursor), false);
+        existing_mask.extend_constant(range.end.saturating_sub(range.start), true);
+        cursor = range.end;
+    }
+    existing_mask.extend_constant(num_rows.saturating_sub(cursor), false);
+
+    let combined: MutableBitmap = pruned_mask
+        .iter()
+        .zip(existing_mask.iter())
+        .map(|(a, b)| a && b)
+        .collect();
+
+    Ok(Filter::Mask(combined.into()))
+}